@@ -0,0 +1,120 @@
+//! 自动更新配置
+
+use crate::updater::BucketConfig;
+
+/// 自动更新配置。
+///
+/// 描述更新所需的仓库坐标与校验信息；各字段均提供链式 `with_*` 构建器，
+/// 未显式设置的字段取 [`Default`] 值。
+#[derive(Debug, Clone)]
+pub struct UpdateConfig {
+    /// GitHub 仓库所有者
+    pub github_owner: String,
+    /// GitHub 仓库名称
+    pub github_repo: String,
+    /// 可执行文件名
+    pub bin_name: String,
+    /// 当前运行的版本号
+    pub current_version: String,
+    /// 用于校验下载产物的 Ed25519 公钥（各 32 字节）。
+    ///
+    /// 为空时跳过签名校验；配置后，任一公钥验证通过即视为可信。
+    pub verifying_keys: Vec<[u8; 32]>,
+    /// Gitee 仓库所有者（用作 GitHub 不可达时的回退下载后端）
+    pub gitee_owner: String,
+    /// Gitee 仓库名称
+    pub gitee_repo: String,
+    /// GitHub API 认证令牌。
+    ///
+    /// 用于访问私有仓库并提升匿名请求的速率限制；为空时回退到 `GITHUB_TOKEN`
+    /// 环境变量。
+    pub auth_token: Option<String>,
+    /// 可选的对象存储后端（S3 / GCS / DigitalOcean Spaces）。
+    ///
+    /// 配置后会作为 GitHub、Gitee 之后的又一个回退下载后端参与更新。
+    pub bucket: Option<BucketConfig>,
+}
+
+impl UpdateConfig {
+    /// 以仓库坐标与当前版本创建配置，其余字段取默认值。
+    pub fn new(
+        github_owner: impl Into<String>,
+        github_repo: impl Into<String>,
+        bin_name: impl Into<String>,
+        current_version: impl Into<String>,
+    ) -> Self {
+        Self {
+            github_owner: github_owner.into(),
+            github_repo: github_repo.into(),
+            bin_name: bin_name.into(),
+            current_version: current_version.into(),
+            ..Default::default()
+        }
+    }
+
+    /// 设置用于校验下载产物的 Ed25519 公钥。
+    pub fn with_verifying_keys(mut self, keys: Vec<[u8; 32]>) -> Self {
+        self.verifying_keys = keys;
+        self
+    }
+
+    /// 设置 Gitee 回退后端的仓库坐标。
+    pub fn with_gitee(
+        mut self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+    ) -> Self {
+        self.gitee_owner = owner.into();
+        self.gitee_repo = repo.into();
+        self
+    }
+
+    /// 设置 GitHub API 认证令牌。
+    pub fn with_auth_token(mut self, token: impl Into<String>) -> Self {
+        self.auth_token = Some(token.into());
+        self
+    }
+
+    /// 设置对象存储回退后端。
+    pub fn with_bucket(mut self, bucket: BucketConfig) -> Self {
+        self.bucket = Some(bucket);
+        self
+    }
+
+    /// GitHub 发布页地址。
+    pub fn github_releases_url(&self) -> String {
+        format!(
+            "https://github.com/{}/{}/releases",
+            self.github_owner, self.github_repo
+        )
+    }
+
+    /// Gitee 发布页地址。
+    pub fn gitee_releases_url(&self) -> String {
+        format!(
+            "https://gitee.com/{}/{}/releases",
+            self.gitee_owner, self.gitee_repo
+        )
+    }
+
+    /// 返回 `(GitHub, Gitee)` 两个发布页下载链接，供界面展示。
+    pub fn download_links(&self) -> (String, String) {
+        (self.github_releases_url(), self.gitee_releases_url())
+    }
+}
+
+impl Default for UpdateConfig {
+    fn default() -> Self {
+        Self {
+            github_owner: String::new(),
+            github_repo: String::new(),
+            bin_name: env!("CARGO_PKG_NAME").to_string(),
+            current_version: env!("CARGO_PKG_VERSION").to_string(),
+            verifying_keys: Vec::new(),
+            gitee_owner: String::new(),
+            gitee_repo: String::new(),
+            auth_token: None,
+            bucket: None,
+        }
+    }
+}
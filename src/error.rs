@@ -15,6 +15,8 @@ pub enum UpdateError {
     FileSystem(String),
     /// 权限错误
     Permission(String),
+    /// 签名校验错误
+    Signature(String),
     /// 配置错误
     Configuration(String),
     /// 其他错误
@@ -31,6 +33,7 @@ impl fmt::Display for UpdateError {
             UpdateError::Version(msg) => write!(f, "版本解析错误: {}", msg),
             UpdateError::FileSystem(msg) => write!(f, "文件系统错误: {}", msg),
             UpdateError::Permission(msg) => write!(f, "权限错误: {}", msg),
+            UpdateError::Signature(msg) => write!(f, "签名校验错误: {}", msg),
             UpdateError::Configuration(msg) => write!(f, "配置错误: {}", msg),
             UpdateError::Other(msg) => write!(f, "其他错误: {}", msg),
             UpdateError::Unknown(msg) => write!(f, "未知错误: {}", msg),
@@ -48,14 +51,20 @@ impl From<anyhow::Error> for UpdateError {
 
 impl From<self_update::errors::Error> for UpdateError {
     fn from(error: self_update::errors::Error) -> Self {
-        match error {
-            self_update::errors::Error::Network(_) => {
-                UpdateError::Network(error.to_string())
-            }
-            self_update::errors::Error::Release(_) => {
-                UpdateError::GitHub(error.to_string())
+        // 认证失败（私有仓库或令牌无效）需要与普通网络错误区分开来。根据 HTTP
+        // 状态码判断，避免把恰好含 "401"/"403" 字样的无关消息误判为权限错误。
+        if let self_update::errors::Error::Reqwest(req_err) = &error {
+            if let Some(status) = req_err.status() {
+                if status.as_u16() == 401 || status.as_u16() == 403 {
+                    return UpdateError::Permission(error.to_string());
+                }
             }
-            _ => UpdateError::Unknown(error.to_string()),
+        }
+        let msg = error.to_string();
+        match error {
+            self_update::errors::Error::Network(_) => UpdateError::Network(msg),
+            self_update::errors::Error::Release(_) => UpdateError::GitHub(msg),
+            _ => UpdateError::Unknown(msg),
         }
     }
 }
@@ -0,0 +1,12 @@
+//! burncloud 自动更新库
+//!
+//! 提供基于 GitHub / Gitee / 对象存储多后端的自愈式自动更新能力：下载产物可经
+//! Ed25519 签名校验后再原子替换正在运行的可执行文件。
+
+mod config;
+mod error;
+mod updater;
+
+pub use config::UpdateConfig;
+pub use error::{UpdateError, UpdateResult};
+pub use updater::{AutoUpdater, Backend, BucketConfig, EndPoint};
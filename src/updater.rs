@@ -1,19 +1,121 @@
 //! 自动更新器核心实现
 
 use crate::{UpdateConfig, UpdateError, UpdateResult};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
 use log::{error, info};
 use self_update::backends::github;
-use semver;
+use std::sync::{Arc, OnceLock};
+
+/// 下载后端。
+///
+/// `update_with_fallback` 会依次尝试每个后端：GitHub 失败（网络异常、限流或
+/// 没有匹配的资产）时自动改用 Gitee，让被 GitHub 限速地区的用户仍能完成自动更新。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// GitHub Releases
+    GitHub,
+    /// Gitee Releases（REST API v5）
+    Gitee,
+    /// 对象存储（S3 / GCS / DigitalOcean Spaces），仅在配置了 `bucket` 时启用
+    Bucket,
+}
+
+/// 对象存储服务端点。
+///
+/// 决定列举与下载所用的基础 URL，覆盖兼容 S3 XML 协议的常见服务。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndPoint {
+    /// Amazon S3
+    S3,
+    /// Amazon S3（双栈 IPv4/IPv6 端点）
+    S3DualStack,
+    /// Google Cloud Storage
+    GCS,
+    /// DigitalOcean Spaces
+    DigitalOceanSpaces,
+}
+
+impl EndPoint {
+    /// 返回该端点在给定桶与区域下的基础 URL（不含尾部斜杠）。
+    fn base_url(&self, bucket_name: &str, region: &str) -> String {
+        match self {
+            EndPoint::S3 => {
+                format!("https://{}.s3.{}.amazonaws.com", bucket_name, region)
+            }
+            EndPoint::S3DualStack => {
+                format!("https://{}.s3.dualstack.{}.amazonaws.com", bucket_name, region)
+            }
+            EndPoint::GCS => format!("https://storage.googleapis.com/{}", bucket_name),
+            EndPoint::DigitalOceanSpaces => {
+                format!("https://{}.{}.digitaloceanspaces.com", bucket_name, region)
+            }
+        }
+    }
+}
+
+/// 对象存储后端配置。
+///
+/// 用于从 S3 / GCS / DigitalOcean Spaces 等桶中列举并安装发布产物，
+/// 形态对齐 `self_update` 的 s3 后端。
+#[derive(Debug, Clone)]
+pub struct BucketConfig {
+    /// 服务端点类型
+    pub endpoint: EndPoint,
+    /// 桶名称
+    pub bucket_name: String,
+    /// 可选的对象前缀（用于过滤某个目录下的产物）
+    pub asset_prefix: Option<String>,
+    /// 区域（GCS 可留空）
+    pub region: String,
+}
+
+impl Backend {
+    /// 按优先级返回所有可能的后端。
+    pub fn all() -> [Backend; 3] {
+        [Backend::GitHub, Backend::Gitee, Backend::Bucket]
+    }
+}
+
+/// Gitee 发布资产。
+#[derive(Debug, Clone, serde::Deserialize)]
+struct GiteeAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+/// GitHub 发布条目（仅用于读取原始 tag 名称并过滤草稿 / 预发布）。
+#[derive(Debug, Clone, serde::Deserialize)]
+struct GithubRelease {
+    tag_name: String,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    prerelease: bool,
+}
+
+/// Gitee 发布条目。
+#[derive(Debug, Clone, serde::Deserialize)]
+struct GiteeRelease {
+    tag_name: String,
+    name: String,
+    #[serde(default)]
+    assets: Vec<GiteeAsset>,
+}
 
 /// 自动更新器
 #[derive(Clone)]
 pub struct AutoUpdater {
     pub(crate) config: UpdateConfig,
+    /// 后台检查结果缓存：未完成时为空，完成后存入最新版本号与名称（无发布时为 `None`）。
+    check_result: Arc<OnceLock<Option<(String, String)>>>,
 }
 
 impl AutoUpdater {
     pub fn new(config: UpdateConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            check_result: Arc::new(OnceLock::new()),
+        }
     }
 
     pub fn with_default_config() -> Self {
@@ -29,6 +131,91 @@ impl AutoUpdater {
     }
 
 
+    /// 在后台线程上启动一次更新检查，结果写入内部缓存。
+    ///
+    /// 立即返回，不阻塞调用方；GUI 或长驻守护进程可随后通过 [`is_outdated`] 轮询状态。
+    /// 重复调用只会在缓存为空时真正发起一次网络请求。
+    ///
+    /// [`is_outdated`]: AutoUpdater::is_outdated
+    pub fn spawn_check(&self) {
+        if self.check_result.get().is_some() {
+            return;
+        }
+
+        let updater = self.clone();
+        std::thread::spawn(move || {
+            // 仅在检查成功时写入缓存；网络 / 限流等错误保持 OnceLock 未设置，
+            // 以便后续 spawn_check 重试，而不是把失败当成“已是最新”永久缓存。
+            if let Ok(outcome) = updater.get_latest_release_info() {
+                let _ = updater.check_result.set(outcome);
+            }
+        });
+    }
+
+    /// 返回后台检查的结果。
+    ///
+    /// 检查尚未完成时返回 `None`；完成后返回 `Some(bool)`，`true` 表示存在比
+    /// `current_version` 更新的版本（按语义化版本比较）。
+    pub fn is_outdated(&self) -> Option<bool> {
+        let cached = self.check_result.get()?;
+        let (latest_version, _) = match cached {
+            Some(info) => info,
+            None => return Some(false),
+        };
+
+        let current_version = self.config.current_version.trim_start_matches('v');
+        let latest_version = latest_version.trim_start_matches('v');
+
+        let outdated = match (
+            semver::Version::parse(current_version),
+            semver::Version::parse(latest_version),
+        ) {
+            (Ok(current), Ok(latest)) => latest > current,
+            _ => latest_version != current_version,
+        };
+        Some(outdated)
+    }
+
+    /// 在后台检查更新，并在发现新版本时弹出一条桌面通知。
+    ///
+    /// 通知内容包含当前版本、最新版本以及 GitHub / Gitee 下载链接。方法立即返回，
+    /// 检查与通知都在独立线程上完成，采用即发即忘（fire-and-forget）语义，任何错误都会被忽略。
+    /// 需要启用 `notify` cargo feature。
+    #[cfg(feature = "notify")]
+    pub fn notify_if_update_available(&self) {
+        let updater = self.clone();
+        std::thread::spawn(move || {
+            if let Ok(true) = updater.sync_check_for_updates() {
+                let latest = updater
+                    .get_latest_release_info()
+                    .ok()
+                    .flatten()
+                    .map(|(version, _)| version)
+                    .unwrap_or_else(|| "未知".to_string());
+                let (github_link, gitee_link) = updater.get_download_links();
+
+                let _ = notify_rust::Notification::new()
+                    .summary("发现新版本")
+                    .body(&format!(
+                        "当前版本 {} → 最新版本 {}\nGitHub: {}\nGitee: {}",
+                        updater.config.current_version, latest, github_link, gitee_link
+                    ))
+                    .show();
+            }
+        });
+    }
+
+    /// 解析用于 GitHub 请求的认证令牌。
+    ///
+    /// 优先使用 `UpdateConfig::auth_token`，否则回退到 `GITHUB_TOKEN` 环境变量。
+    /// 令牌可用于访问私有仓库并提升匿名请求的速率限制。
+    fn auth_token(&self) -> Option<String> {
+        self.config
+            .auth_token
+            .clone()
+            .or_else(|| std::env::var("GITHUB_TOKEN").ok())
+    }
+
     pub fn current_version(&self) -> &str {
         &self.config.current_version
     }
@@ -45,93 +232,561 @@ impl AutoUpdater {
         self.config.download_links()
     }
 
+    /// 按优先级返回当前配置下实际启用的后端。
+    ///
+    /// 对象存储后端仅在配置了 [`UpdateConfig::bucket`] 时参与。
+    fn backends(&self) -> Vec<Backend> {
+        Backend::all()
+            .into_iter()
+            .filter(|backend| match backend {
+                Backend::Bucket => self.config.bucket.is_some(),
+                _ => true,
+            })
+            .collect()
+    }
+
     pub fn get_latest_release_info(&self) -> UpdateResult<Option<(String, String)>> {
         info!("获取最新发布版本信息..");
 
+        let mut last_err = None;
+        let mut saw_empty = false;
+        for backend in self.backends() {
+            match self.latest_release(backend) {
+                Ok(Some(info)) => return Ok(Some(info)),
+                // 该后端可达但无匹配发布，继续尝试后续后端而非直接放弃。
+                Ok(None) => saw_empty = true,
+                Err(e) => {
+                    error!("{:?} 获取发布信息失败: {}", backend, e);
+                    last_err = Some(e);
+                }
+            }
+        }
+        if saw_empty {
+            return Ok(None);
+        }
+        Err(last_err.unwrap_or_else(|| UpdateError::GitHub("未找到任何发布版本".to_string())))
+    }
+
+    pub fn needs_update(&self) -> UpdateResult<bool> {
+        info!("检查是否需要更新..");
+
+        let (latest_version, _) = self
+            .get_latest_release_info()?
+            .ok_or_else(|| UpdateError::GitHub("未找到任何发布版本".to_string()))?;
+
+        let current_version = self
+            .config
+            .current_version
+            .trim_start_matches('v')
+            .to_string();
+        let latest_version = latest_version.trim_start_matches('v').to_string();
+
+        match (
+            semver::Version::parse(&current_version),
+            semver::Version::parse(&latest_version),
+        ) {
+            (Ok(current), Ok(latest)) => Ok(latest > current),
+            _ => Ok(latest_version != current_version),
+        }
+    }
+
+    /// 在指定发布线（如 `stable-1.6`）内选择最高补丁版本，而非全局最新版本。
+    ///
+    /// 返回该发布线中语义化版本最高、且比 `current_version` 更新的版本号；
+    /// 若该发布线没有更新的版本则返回 `None`。行为对齐 solana-install 的
+    /// “将某个命名发布线更新到其最新补丁” 语义。
+    pub fn needs_update_in_channel(&self, channel: &str) -> UpdateResult<Option<String>> {
+        info!("检查发布线 {} 内的更新..", channel);
+
+        // 按真实 tag 匹配发布线，再在该线内取内嵌语义化版本最高者。直接对
+        // self_update 解析后的 version 做字符串前缀匹配会漏掉命名发布线
+        // （如 stable-1.6），且会把 1.6 误匹配到 1.60.x。
+        let highest = self
+            .github_release_tags()?
+            .into_iter()
+            .filter(|tag| tag_in_channel(tag, channel))
+            .filter_map(|tag| extract_semver(&tag).map(|v| (v, tag)))
+            .max_by(|a, b| a.0.cmp(&b.0));
+
+        let (highest_version, highest_tag) = match highest {
+            Some(pair) => pair,
+            None => return Ok(None),
+        };
+
+        let current = self.config.current_version.trim_start_matches('v');
+        let is_newer = match semver::Version::parse(current) {
+            Ok(current) => highest_version > current,
+            Err(_) => highest_tag.trim_start_matches('v') != current,
+        };
+
+        Ok(is_newer.then_some(highest_tag))
+    }
+
+    /// 安装指定 tag 的发布版本，支持强制降级以及回滚到某个已知良好版本。
+    ///
+    /// 当请求的 tag 不存在时返回 [`UpdateError::Version`]，成功时返回已安装的版本号。
+    pub fn sync_update_to(&self, version: &str) -> UpdateResult<String> {
+        info!("安装指定版本 {}..", version);
+
+        let wanted = version.trim_start_matches('v');
         let target = self_update::get_target();
-        let releases = github::ReleaseList::configure()
+
+        let mut list_builder = github::ReleaseList::configure();
+        list_builder
             .repo_owner(&self.config.github_owner)
             .repo_name(&self.config.github_repo)
-            .with_target(&target)
+            .with_target(target);
+        if let Some(token) = self.auth_token() {
+            list_builder.auth_token(&token);
+        }
+        let releases = list_builder
             .build()
             .map_err(UpdateError::from)?
             .fetch()
             .map_err(UpdateError::from)?;
 
-        if let Some(latest_release) = releases.first() {
-            Ok(Some((latest_release.version.clone(), latest_release.name.clone())))
-        } else {
-            Ok(None)
+        // 同时接受命名发布线 tag（如 stable-1.6.3）与纯语义化版本：按原始字符串或
+        // 内嵌语义化版本匹配，使 needs_update_in_channel 返回的 tag 也能安装。
+        let wanted_semver = extract_semver(version);
+        let release = releases
+            .iter()
+            .find(|r| {
+                r.version == version
+                    || r.version.trim_start_matches('v') == wanted
+                    || (wanted_semver.is_some() && extract_semver(&r.version) == wanted_semver)
+            })
+            .ok_or_else(|| UpdateError::Version(format!("发布版本 {} 不存在", version)))?;
+
+        // 安装任意指定版本（含降级 / 回滚）前，同样要校验该版本二进制的签名。
+        if !self.config.verifying_keys.is_empty() {
+            let bin_asset = release
+                .asset_for(target, Some(&self.config.bin_name))
+                .ok_or_else(|| {
+                    UpdateError::Signature(format!("未找到匹配 {} 的二进制资产", target))
+                })?;
+            let sig_name = format!("{}.sig", self.config.bin_name);
+            let sig_url = release
+                .assets
+                .iter()
+                .find(|a| a.name == sig_name)
+                .map(|a| a.download_url.as_str());
+            let token = self.auth_token();
+            let bin_bytes = download_asset_bytes_auth(&bin_asset.download_url, token.as_deref())?;
+            self.verify_signature_over(&bin_bytes, sig_url, token.as_deref())?;
+        }
+
+        let mut builder = github::Update::configure();
+        builder
+            .repo_owner(&self.config.github_owner)
+            .repo_name(&self.config.github_repo)
+            .target(target)
+            .bin_name(&self.config.bin_name)
+            .current_version(&self.config.current_version)
+            .target_version_tag(version)
+            .show_download_progress(false)
+            .no_confirm(true);
+        if let Some(token) = self.auth_token() {
+            builder.auth_token(&token);
+        }
+        let update = builder.build().map_err(UpdateError::from)?;
+
+        let status = update.update().map_err(UpdateError::from)?;
+        info!("已安装版本: {}", status.version());
+        Ok(status.version().to_string())
+    }
+
+    /// 获取 GitHub 上全部发布的原始 tag 名称。
+    ///
+    /// 直接读取 GitHub REST API 的 `tag_name`，保留 self_update 解析时丢弃的
+    /// 发布线前缀（如 `stable-`），以便按发布线精确匹配。
+    fn github_release_tags(&self) -> UpdateResult<Vec<String>> {
+        let url = format!(
+            "https://api.github.com/repos/{}/{}/releases?per_page=100",
+            self.config.github_owner, self.config.github_repo
+        );
+        let body = http_get_bytes(
+            &url,
+            "application/vnd.github+json",
+            self.auth_token().as_deref(),
+        )?;
+        let releases: Vec<GithubRelease> = serde_json::from_slice(&body)
+            .map_err(|e| UpdateError::GitHub(format!("解析 GitHub 发布列表失败: {}", e)))?;
+        // 只考虑已发布的正式版本，草稿与预发布不应作为自动更新目标。
+        Ok(releases
+            .into_iter()
+            .filter(|r| !r.draft && !r.prerelease)
+            .map(|r| r.tag_name)
+            .collect())
+    }
+
+    /// 从指定后端获取最新发布的版本号与名称。
+    fn latest_release(&self, backend: Backend) -> UpdateResult<Option<(String, String)>> {
+        match backend {
+            Backend::GitHub => {
+                let target = self_update::get_target();
+                let mut builder = github::ReleaseList::configure();
+                builder
+                    .repo_owner(&self.config.github_owner)
+                    .repo_name(&self.config.github_repo)
+                    .with_target(target);
+                if let Some(token) = self.auth_token() {
+                    builder.auth_token(&token);
+                }
+                let releases = builder
+                    .build()
+                    .map_err(UpdateError::from)?
+                    .fetch()
+                    .map_err(UpdateError::from)?;
+
+                Ok(releases
+                    .first()
+                    .map(|r| (r.version.clone(), r.name.clone())))
+            }
+            Backend::Gitee => Ok(self
+                .gitee_latest_release()?
+                .map(|r| (r.tag_name.trim_start_matches('v').to_string(), r.name))),
+            Backend::Bucket => Ok(self.bucket_latest_asset()?.map(|(version, url)| {
+                let name = url.rsplit('/').next().unwrap_or_default().to_string();
+                (version.to_string(), name)
+            })),
         }
     }
 
-    pub fn needs_update(&self) -> UpdateResult<bool> {
-        info!("检查是否需要更新..");
+    /// 拉取 Gitee 的全部发布条目。
+    fn gitee_releases(&self) -> UpdateResult<Vec<GiteeRelease>> {
+        let url = format!(
+            "https://gitee.com/api/v5/repos/{}/{}/releases",
+            self.config.gitee_owner, self.config.gitee_repo
+        );
+        let body = download_asset_bytes(&url)?;
+        serde_json::from_slice(&body)
+            .map_err(|e| UpdateError::GitHub(format!("解析 Gitee 发布列表失败: {}", e)))
+    }
+
+    /// 返回 Gitee 上语义化版本最高的发布。
+    fn gitee_latest_release(&self) -> UpdateResult<Option<GiteeRelease>> {
+        let releases = self.gitee_releases()?;
+        let latest = releases.into_iter().max_by(|a, b| {
+            let va = semver::Version::parse(a.tag_name.trim_start_matches('v')).ok();
+            let vb = semver::Version::parse(b.tag_name.trim_start_matches('v')).ok();
+            va.cmp(&vb)
+        });
+        Ok(latest)
+    }
+
+    /// 列举对象存储桶中匹配当前平台的最新产物，返回 `(版本号, 下载 URL)`。
+    ///
+    /// 发起桶的 XML 列举请求，用 quick-xml 解析 `<Key>` 条目，按 `asset_prefix`
+    /// 与 [`self_update::get_target`] 过滤，再从键名中提取内嵌的语义化版本挑选最新者。
+    fn bucket_latest_asset(&self) -> UpdateResult<Option<(semver::Version, String)>> {
+        let bucket = self
+            .config
+            .bucket
+            .as_ref()
+            .ok_or_else(|| UpdateError::Configuration("未配置对象存储后端".to_string()))?;
+
+        let base_url = bucket.endpoint.base_url(&bucket.bucket_name, &bucket.region);
+        let mut list_url = format!("{}/?list-type=2", base_url);
+        if let Some(prefix) = &bucket.asset_prefix {
+            list_url.push_str(&format!("&prefix={}", prefix));
+        }
+
+        let body = download_asset_bytes(&list_url)?;
+        let keys = parse_bucket_keys(&body)?;
 
         let target = self_update::get_target();
-        let releases = github::ReleaseList::configure()
+        let newest = keys
+            .into_iter()
+            .filter(|key| key.contains(target))
+            // 排除签名等旁路文件，避免把 <binary>.sig 误选为最新二进制产物。
+            .filter(|key| !key.ends_with(".sig"))
+            .filter(|key| {
+                bucket
+                    .asset_prefix
+                    .as_ref()
+                    .map(|p| key.starts_with(p))
+                    .unwrap_or(true)
+            })
+            .filter_map(|key| extract_semver(&key).map(|v| (v, key)))
+            .max_by(|a, b| a.0.cmp(&b.0));
+
+        Ok(newest.map(|(version, key)| (version, format!("{}/{}", base_url, key))))
+    }
+
+    /// 从对象存储桶下载最新产物并原地替换正在运行的可执行文件。
+    ///
+    /// 与 GitHub 路径一样执行原子替换，成功时返回已安装的版本号。
+    pub fn sync_update_from_bucket(&self) -> UpdateResult<String> {
+        info!("尝试从对象存储更新..");
+
+        let (version, download_url) = self
+            .bucket_latest_asset()?
+            .ok_or_else(|| UpdateError::GitHub("对象存储中未找到匹配的产物".to_string()))?;
+
+        // 与 GitHub 路径（由 self_update 把关）一致：不比当前版本新则不重装。
+        if !self.is_newer_than_current(&version.to_string()) {
+            info!("已是最新版本");
+            return Ok(self.config.current_version.clone());
+        }
+
+        let asset_bytes = download_asset_bytes(&download_url)?;
+        self.verify_signature_over(&asset_bytes, Some(&format!("{}.sig", download_url)), None)?;
+
+        let tmp_dir = tempfile::Builder::new()
+            .prefix("burncloud-update")
+            .tempdir()
+            .map_err(|e| UpdateError::FileSystem(e.to_string()))?;
+        let file_name = download_url.rsplit('/').next().unwrap_or(&self.config.bin_name);
+        let tmp_archive_path = tmp_dir.path().join(file_name);
+        std::fs::write(&tmp_archive_path, &asset_bytes)
+            .map_err(|e| UpdateError::FileSystem(e.to_string()))?;
+
+        let bin_path_in_archive = std::path::Path::new(&self.config.bin_name);
+        self_update::Extract::from_source(&tmp_archive_path)
+            .extract_file(tmp_dir.path(), bin_path_in_archive)
+            .map_err(UpdateError::from)?;
+
+        let new_exe = tmp_dir.path().join(&self.config.bin_name);
+        let current_exe =
+            std::env::current_exe().map_err(|e| UpdateError::FileSystem(e.to_string()))?;
+        let tmp_replacement = tmp_dir.path().join("replacement_tmp");
+
+        self_update::Move::from_source(&new_exe)
+            .replace_using_temp(&tmp_replacement)
+            .to_dest(&current_exe)
+            .map_err(UpdateError::from)?;
+
+        info!("更新成功，新版本: {}", version);
+        Ok(version.to_string())
+    }
+
+    /// 校验 GitHub 最新发布二进制的 Ed25519 签名。
+    ///
+    /// 下载与二进制同名的 `<bin_name>.sig` 资产并验证后返回。未配置公钥时直接放行。
+    /// 该校验在 GitHub 后端安装路径内部进行，因此 GitHub 不可达时会连同安装一起失败，
+    /// 从而让 [`sync_update`] 继续尝试 Gitee / 对象存储后端。
+    ///
+    /// [`sync_update`]: AutoUpdater::sync_update
+    fn verify_github_signature(&self) -> UpdateResult<()> {
+        if self.config.verifying_keys.is_empty() {
+            return Ok(());
+        }
+
+        info!("校验 GitHub 新版本二进制签名..");
+
+        let target = self_update::get_target();
+        let mut builder = github::ReleaseList::configure();
+        builder
             .repo_owner(&self.config.github_owner)
             .repo_name(&self.config.github_repo)
-            .with_target(&target)
+            .with_target(target);
+        if let Some(token) = self.auth_token() {
+            builder.auth_token(&token);
+        }
+        let releases = builder
             .build()
             .map_err(UpdateError::from)?
             .fetch()
             .map_err(UpdateError::from)?;
 
-        if let Some(latest_release) = releases.first() {
-            let current_version = self
-                .config
-                .current_version
-                .trim_start_matches('v')
-                .to_string();
-            let latest_version = latest_release.version.to_string();
-
-            match (
-                semver::Version::parse(&current_version),
-                semver::Version::parse(&latest_version),
-            ) {
-                (Ok(current), Ok(latest)) => Ok(latest > current),
-                _ => Ok(latest_version != current_version),
+        let latest_release = releases
+            .first()
+            .ok_or_else(|| UpdateError::GitHub("未找到任何发布版本".to_string()))?;
+
+        let bin_asset = latest_release
+            .asset_for(target, Some(&self.config.bin_name))
+            .ok_or_else(|| {
+                UpdateError::Signature(format!("未找到匹配 {} 的二进制资产", target))
+            })?;
+        let sig_name = format!("{}.sig", self.config.bin_name);
+        let sig_asset = latest_release
+            .assets
+            .iter()
+            .find(|a| a.name == sig_name)
+            .ok_or_else(|| UpdateError::Signature(format!("缺少签名资产 {}", sig_name)))?;
+
+        let token = self.auth_token();
+        let bin_bytes = download_asset_bytes_auth(&bin_asset.download_url, token.as_deref())?;
+        self.verify_signature_over(&bin_bytes, Some(&sig_asset.download_url), token.as_deref())
+    }
+
+    /// 对实际要安装的字节校验 Ed25519 签名。
+    ///
+    /// 未配置公钥时直接返回 `Ok(())`；否则从 `sig_url` 下载 64 字节签名（私有 GitHub
+    /// 仓库需经 `sig_auth` 令牌认证），对 `bin_bytes` 逐个公钥验证，任一公钥通过即视为
+    /// 可信。缺少签名资产或无公钥匹配时返回 [`UpdateError::Signature`]。
+    fn verify_signature_over(
+        &self,
+        bin_bytes: &[u8],
+        sig_url: Option<&str>,
+        sig_auth: Option<&str>,
+    ) -> UpdateResult<()> {
+        if self.config.verifying_keys.is_empty() {
+            return Ok(());
+        }
+
+        let sig_url =
+            sig_url.ok_or_else(|| UpdateError::Signature("缺少签名资产".to_string()))?;
+        let sig_bytes = download_asset_bytes_auth(sig_url, sig_auth)?;
+
+        let sig_array: [u8; 64] = sig_bytes
+            .as_slice()
+            .try_into()
+            .map_err(|_| UpdateError::Signature("签名长度不是 64 字节".to_string()))?;
+        let signature = Signature::from_bytes(&sig_array);
+
+        for key_bytes in &self.config.verifying_keys {
+            if let Ok(key) = VerifyingKey::from_bytes(key_bytes) {
+                if key.verify(bin_bytes, &signature).is_ok() {
+                    info!("签名校验通过");
+                    return Ok(());
+                }
             }
-        } else {
-            error!("未找到任何发布版本");
-            Err(UpdateError::GitHub("未找到任何发布版本".to_string()))
         }
+
+        Err(UpdateError::Signature(
+            "没有任何配置的公钥能够验证该签名".to_string(),
+        ))
     }
 
     pub fn sync_update(&self) -> UpdateResult<()> {
         info!("开始同步更新应用程序..");
 
-        let target = self_update::get_target();
+        let mut last_err = None;
+        for backend in self.backends() {
+            match self.sync_update_with(backend) {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    error!("{:?} 更新失败，尝试下一个后端: {}", backend, e);
+                    last_err = Some(e);
+                }
+            }
+        }
+        Err(last_err.unwrap_or_else(|| UpdateError::Unknown("没有可用的更新后端".to_string())))
+    }
 
-        let update = github::Update::configure()
-            .repo_owner(&self.config.github_owner)
-            .repo_name(&self.config.github_repo)
-            .target(&target)
-            .bin_name(&self.config.bin_name)
-            .current_version(&self.config.current_version)
-            .show_download_progress(false)
-            .no_confirm(true)
-            .build()
-            .map_err(UpdateError::from)?;
+    /// 使用指定后端执行一次原地更新。
+    fn sync_update_with(&self, backend: Backend) -> UpdateResult<()> {
+        match backend {
+            Backend::GitHub => {
+                self.verify_github_signature()?;
 
-        let status = update.update().map_err(UpdateError::from)?;
-        if status.updated() {
-            info!("更新成功，新版本: {}", status.version());
-        } else {
+                let target = self_update::get_target();
+                let mut builder = github::Update::configure();
+                builder
+                    .repo_owner(&self.config.github_owner)
+                    .repo_name(&self.config.github_repo)
+                    .target(target)
+                    .bin_name(&self.config.bin_name)
+                    .current_version(&self.config.current_version)
+                    .show_download_progress(false)
+                    .no_confirm(true);
+                if let Some(token) = self.auth_token() {
+                    builder.auth_token(&token);
+                }
+                let update = builder.build().map_err(UpdateError::from)?;
+
+                let status = update.update().map_err(UpdateError::from)?;
+                if status.updated() {
+                    info!("更新成功，新版本: {}", status.version());
+                } else {
+                    info!("已是最新版本");
+                }
+                Ok(())
+            }
+            Backend::Gitee => self.sync_update_gitee(),
+            Backend::Bucket => self.sync_update_from_bucket().map(|_| ()),
+        }
+    }
+
+    /// 判断给定版本是否比 `current_version` 更新。
+    ///
+    /// 语义化版本比较；任一侧解析失败时退化为字符串不等判断。
+    fn is_newer_than_current(&self, version: &str) -> bool {
+        let current = self.config.current_version.trim_start_matches('v');
+        let candidate = version.trim_start_matches('v');
+        match (
+            semver::Version::parse(current),
+            semver::Version::parse(candidate),
+        ) {
+            (Ok(current), Ok(candidate)) => candidate > current,
+            _ => candidate != current,
+        }
+    }
+
+    /// 从 Gitee 下载匹配当前平台的资产并原地替换正在运行的可执行文件。
+    fn sync_update_gitee(&self) -> UpdateResult<()> {
+        info!("尝试从 Gitee 更新..");
+
+        let target = self_update::get_target();
+        let release = self
+            .gitee_latest_release()?
+            .ok_or_else(|| UpdateError::GitHub("Gitee 未找到任何发布版本".to_string()))?;
+
+        // 与 GitHub 路径（由 self_update 把关）一致：不比当前版本新则不重装。
+        if !self.is_newer_than_current(&release.tag_name) {
             info!("已是最新版本");
+            return Ok(());
         }
+
+        let asset = release
+            .assets
+            .iter()
+            .find(|a| a.name.contains(target))
+            .ok_or_else(|| {
+                UpdateError::GitHub(format!("Gitee 上未找到匹配 {} 的资产", target))
+            })?;
+
+        let asset_bytes = download_asset_bytes(&asset.browser_download_url)?;
+        let sig_name = format!("{}.sig", asset.name);
+        let sig_url = release
+            .assets
+            .iter()
+            .find(|a| a.name == sig_name)
+            .map(|a| a.browser_download_url.as_str());
+        self.verify_signature_over(&asset_bytes, sig_url, None)?;
+
+        let tmp_dir = tempfile::Builder::new()
+            .prefix("burncloud-update")
+            .tempdir()
+            .map_err(|e| UpdateError::FileSystem(e.to_string()))?;
+        let tmp_archive_path = tmp_dir.path().join(&asset.name);
+        std::fs::write(&tmp_archive_path, &asset_bytes)
+            .map_err(|e| UpdateError::FileSystem(e.to_string()))?;
+
+        let bin_path_in_archive = std::path::Path::new(&self.config.bin_name);
+        self_update::Extract::from_source(&tmp_archive_path)
+            .extract_file(tmp_dir.path(), bin_path_in_archive)
+            .map_err(UpdateError::from)?;
+
+        let new_exe = tmp_dir.path().join(&self.config.bin_name);
+        let current_exe =
+            std::env::current_exe().map_err(|e| UpdateError::FileSystem(e.to_string()))?;
+        let tmp_replacement = tmp_dir.path().join("replacement_tmp");
+
+        self_update::Move::from_source(&new_exe)
+            .replace_using_temp(&tmp_replacement)
+            .to_dest(&current_exe)
+            .map_err(UpdateError::from)?;
+
+        info!("更新成功，新版本: {}", release.tag_name);
         Ok(())
     }
 
+
     pub fn sync_check_for_updates(&self) -> UpdateResult<bool> {
         info!("同步检查更新中...");
 
         let target = self_update::get_target();
-        let releases = github::ReleaseList::configure()
+        let mut builder = github::ReleaseList::configure();
+        builder
             .repo_owner(&self.config.github_owner)
             .repo_name(&self.config.github_repo)
-            .with_target(&target)
+            .with_target(target);
+        if let Some(token) = self.auth_token() {
+            builder.auth_token(&token);
+        }
+        let releases = builder
             .build()
             .map_err(UpdateError::from)?
             .fetch()
@@ -149,3 +804,148 @@ impl AutoUpdater {
     }
 }
 
+/// 从 S3 XML 列举响应中解析所有 `<Key>` 条目。
+fn parse_bucket_keys(body: &[u8]) -> UpdateResult<Vec<String>> {
+    use quick_xml::events::Event;
+    use quick_xml::Reader;
+
+    let mut reader = Reader::from_reader(body);
+    reader.config_mut().trim_text(true);
+
+    let mut keys = Vec::new();
+    let mut in_key = false;
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e)) if e.name().as_ref() == b"Key" => in_key = true,
+            Ok(Event::End(e)) if e.name().as_ref() == b"Key" => in_key = false,
+            Ok(Event::Text(e)) if in_key => {
+                let text = e
+                    .unescape()
+                    .map_err(|e| UpdateError::GitHub(format!("解析桶列表失败: {}", e)))?;
+                keys.push(text.into_owned());
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(UpdateError::GitHub(format!("解析桶列表失败: {}", e))),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(keys)
+}
+
+/// 判断 tag 是否属于某条发布线。
+///
+/// 在去掉可选前导 `v` 后，要求 tag 以 `channel` 开头且紧随版本分隔符
+/// （`.`、`-` 或字符串结尾）。这样 `1.6` 能匹配 `1.6.3` 而不会误匹配 `1.60.0`，
+/// 命名发布线如 `stable-1.6` 也能匹配 `stable-1.6.3`。
+fn tag_in_channel(tag: &str, channel: &str) -> bool {
+    let tag = tag.trim_start_matches('v');
+    let channel = channel.trim_start_matches('v');
+    match tag.strip_prefix(channel) {
+        Some(rest) => rest.is_empty() || rest.starts_with('.') || rest.starts_with('-'),
+        None => false,
+    }
+}
+
+/// 从对象键名中提取内嵌的语义化版本，例如 `app/v1.2.3/app-x86_64-linux`。
+fn extract_semver(key: &str) -> Option<semver::Version> {
+    key.split(|c: char| !c.is_ascii_digit() && c != '.')
+        .filter_map(|token| semver::Version::parse(token).ok())
+        .max()
+}
+
+/// 下载资产的原始字节，供签名校验等场景直接读取内容。
+fn download_asset_bytes(url: &str) -> UpdateResult<Vec<u8>> {
+    download_asset_bytes_auth(url, None)
+}
+
+/// 下载资产的原始字节，可附带 GitHub 认证令牌。
+///
+/// 私有仓库的资产 URL 对匿名请求返回 401/404，签名校验下载同样需要带上令牌。
+fn download_asset_bytes_auth(url: &str, auth_token: Option<&str>) -> UpdateResult<Vec<u8>> {
+    http_get_bytes(url, "application/octet-stream", auth_token)
+}
+
+/// 发起一次 GET 请求并读取响应体字节。
+///
+/// `accept` 指定 `Accept` 头（二进制资产用 `application/octet-stream`，GitHub
+/// JSON API 用 `application/vnd.github+json`）；`auth_token` 可附带 GitHub 认证
+/// 令牌以访问私有仓库或提升速率限制。
+fn http_get_bytes(url: &str, accept: &str, auth_token: Option<&str>) -> UpdateResult<Vec<u8>> {
+    let mut request = reqwest::blocking::Client::builder()
+        .build()
+        .map_err(|e| UpdateError::Network(e.to_string()))?
+        .get(url)
+        .header(reqwest::header::ACCEPT, accept)
+        .header(reqwest::header::USER_AGENT, "burncloud-auto-update");
+    if let Some(token) = auth_token {
+        request = request.header(reqwest::header::AUTHORIZATION, format!("Bearer {}", token));
+    }
+    let resp = request
+        .send()
+        .map_err(|e| UpdateError::Network(e.to_string()))?;
+
+    let status = resp.status();
+    if status.as_u16() == 401 || status.as_u16() == 403 {
+        return Err(UpdateError::Permission(format!(
+            "下载资产被拒绝，状态码: {}",
+            status
+        )));
+    }
+    if !status.is_success() {
+        return Err(UpdateError::Network(format!(
+            "下载资产失败，状态码: {}",
+            status
+        )));
+    }
+
+    resp.bytes()
+        .map(|b| b.to_vec())
+        .map_err(|e| UpdateError::Network(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tag_in_channel_requires_component_boundary() {
+        // 前缀相同但不在版本分量边界上，必须判为不匹配。
+        assert!(!tag_in_channel("v1.60.0", "1.6"));
+        // 边界处（点、连字符或完全相等）才算命中该通道。
+        assert!(tag_in_channel("v1.6.3", "1.6"));
+        assert!(tag_in_channel("stable-1.6.3", "stable-1.6"));
+        assert!(tag_in_channel("stable-1.6", "stable-1.6"));
+    }
+
+    #[test]
+    fn extract_semver_ignores_platform_digits() {
+        // 不能把 x86_64 中的 86 / 64 误当作版本号。
+        assert_eq!(
+            extract_semver("app/v1.2.3/app-x86_64-linux"),
+            Some(semver::Version::parse("1.2.3").unwrap())
+        );
+        assert_eq!(extract_semver("app-x86_64-linux"), None);
+    }
+
+    #[test]
+    fn parse_bucket_keys_extracts_key_entries() {
+        let body = br#"<?xml version="1.0" encoding="UTF-8"?>
+<ListBucketResult>
+  <Contents><Key>app/v1.2.3/app-x86_64-linux.tar.gz</Key></Contents>
+  <Contents><Key>app/v1.2.3/app-x86_64-linux.tar.gz.sig</Key></Contents>
+</ListBucketResult>"#;
+        let keys = parse_bucket_keys(body).unwrap();
+        assert_eq!(
+            keys,
+            vec![
+                "app/v1.2.3/app-x86_64-linux.tar.gz".to_string(),
+                "app/v1.2.3/app-x86_64-linux.tar.gz.sig".to_string(),
+            ]
+        );
+    }
+}
+